@@ -0,0 +1,156 @@
+//! Async mirror of the `ask` family, for embedding this crate in an
+//! existing async CLI/TUI event loop instead of blocking a thread on
+//! `rustyline`/stdin.
+//!
+//! The conversion logic (`Askable::convert`) is shared with the sync API;
+//! only the I/O layer differs, so a single `Askable` impl works in both
+//! worlds.
+
+use std::io::Write;
+
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+
+use crate::{handle_failure, tokenize, Askable, InterviewError, Result, Separator};
+
+/// Marker trait unifying `Askable` for use by the async subsystem.
+///
+/// Blanket-implemented for every `Askable` type, so there is nothing extra
+/// to implement: if a type already works with [`crate::ask`], it already
+/// works with [`ask_async`].
+pub trait AsyncAskable: Askable {}
+impl<T: Askable> AsyncAskable for T {}
+
+async fn get_str_async<R, S>(reader: &mut R, prompt_str: S) -> Result<String>
+where
+    R: AsyncBufRead + Unpin,
+    S: AsRef<str>
+{
+    print!("{}", prompt_str.as_ref());
+    std::io::stdout().flush().expect("could not flush stdout");
+    let mut buffer = String::new();
+    match reader.read_line(&mut buffer).await {
+        Ok(0) => handle_failure(InterviewError::Eof),
+        Ok(_) => Ok(buffer.trim().to_owned()),
+        Err(source) => handle_failure(InterviewError::Io { source })
+    }
+}
+
+/// Ask the user for a value of type T, reading a line from `reader`.
+///
+/// Input is read only once.
+///
+/// # Arguments
+///
+/// * `reader`: async buffered reader to read the line from
+/// * `prompt_str`: prompt displayed to the user
+///
+/// returns: Result<T, InterviewError>
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn run() {
+/// use interviewer::ask_async;
+/// use tokio::io::BufReader;
+/// let mut reader = BufReader::new(tokio::io::stdin());
+/// let s: i32 = ask_async(&mut reader, "enter an i32: ").await.unwrap();
+/// println!("{}", s);
+/// # }
+/// ```
+pub async fn ask_async<T: AsyncAskable, R, S>(reader: &mut R, prompt_str: S) -> Result<T>
+where
+    R: AsyncBufRead + Unpin,
+    S: AsRef<str>
+{
+    let input = get_str_async(reader, prompt_str).await?;
+    T::convert(&input)
+}
+
+/// Ask the user for a value of type T, reading lines from `reader`. The user
+/// is prompted repeatedly until a valid value is provided.
+///
+/// Input is read multiple times.
+///
+/// Like its sync twin `ask_until`, this has no channel to report a read
+/// failure through and so honours `FailurePolicy::Exit`'s `process::exit`
+/// by way of `get_str_async`/`handle_failure`. Under `FailurePolicy::Return`
+/// there is nothing sensible to return either, but exiting the whole host
+/// process would defeat the point of the async API, so this panics instead
+/// — unwinding just the calling task, which an embedding executor (e.g. a
+/// `tokio::spawn`ed task) can observe via its `JoinHandle` rather than
+/// having the entire process killed out from under it.
+///
+/// # Arguments
+///
+/// * `reader`: async buffered reader to read lines from
+/// * `prompt_str`: prompt displayed to the user
+///
+/// returns: T
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn run() {
+/// use interviewer::ask_until_async;
+/// use tokio::io::BufReader;
+/// let mut reader = BufReader::new(tokio::io::stdin());
+/// let s: i32 = ask_until_async(&mut reader, "enter an i32: ").await;
+/// println!("{}", s);
+/// # }
+/// ```
+pub async fn ask_until_async<T: AsyncAskable, R, S>(reader: &mut R, prompt_str: S) -> T
+where
+    R: AsyncBufRead + Unpin,
+    S: AsRef<str>
+{
+    loop {
+        let input = match get_str_async(reader, &prompt_str).await {
+            Ok(input) => input,
+            // Only reachable under FailurePolicy::Return: FailurePolicy::Exit
+            // already process::exit'd inside handle_failure.
+            Err(e) => panic!("{}", e)
+        };
+        if let Ok(s) = T::convert(&input) {
+            return s;
+        }
+    }
+}
+
+/// Ask the user for multiple values of type T separated by delimiter,
+/// reading a line from `reader`.
+///
+/// Input is read only once.
+///
+/// # Arguments
+///
+/// * `reader`: async buffered reader to read the line from
+/// * `prompt_str`: prompt displayed to the user
+/// * `sep`: delimiter between values
+///
+/// returns: Result<Vec<T>, InterviewError>
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn run() {
+/// use interviewer::ask_many_async;
+/// use interviewer::Separator::Whitespace;
+/// use tokio::io::BufReader;
+/// let mut reader = BufReader::new(tokio::io::stdin());
+/// let s: Vec<i32> = ask_many_async(&mut reader, "enter multiple i32s: ", Whitespace).await.unwrap();
+/// println!("{:?}", s);
+/// # }
+/// ```
+pub async fn ask_many_async<T: AsyncAskable, R, S>(reader: &mut R, prompt_str: S, sep: Separator<'_>) -> Result<Vec<T>>
+where
+    R: AsyncBufRead + Unpin,
+    S: AsRef<str>
+{
+    let raw = get_str_async(reader, prompt_str).await?;
+    let tokens = tokenize(raw, sep);
+    let mut v = Vec::with_capacity(tokens.len());
+    for x in tokens {
+        v.push(T::convert(x)?);
+    }
+    Ok(v)
+}
@@ -8,6 +8,12 @@ use std::sync::{Arc, Mutex};
 
 use custom_error::custom_error;
 use lazy_static::lazy_static;
+use zeroize::Zeroize;
+
+#[cfg(feature = "async")]
+mod asyncio;
+#[cfg(feature = "async")]
+pub use asyncio::*;
 
 lazy_static! {
     static ref PARSE_QUOTES: Arc<Mutex<bool>> = Arc::new(Mutex::new(false));
@@ -67,7 +73,73 @@ pub fn set_consumable_quotes(b: bool) { *Arc::clone(&PARSE_QUOTES).lock().unwrap
 /// Result wrapper containing `InterviewError`.
 pub type Result<T> = std::result::Result<T, InterviewError>;
 custom_error! {pub InterviewError
-    ParseError{origin: String, target: String} = "Could not parse \"{origin}\" as {target}"
+    ParseError{origin: String, target: String} = "Could not parse \"{origin}\" as {target}",
+    Interrupted = "input was interrupted (Ctrl-C)",
+    Eof = "input reached end-of-file (Ctrl-D)",
+    Io{source: std::io::Error} = "I/O error while reading input: {source}"
+}
+
+/// Controls how `Interrupted`/`Eof`/`Io` failures from reading input are
+/// handled once they can no longer simply be ignored.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FailurePolicy {
+    /// Legacy behaviour: print the error and `process::exit` (exit 0 on
+    /// Ctrl-C, exit 1 on EOF and any other I/O error). This is the default.
+    Exit,
+    /// Surface the failure as `Err(InterviewError)`/`None` through the
+    /// `Result`/`Option`-returning functions instead of exiting. Functions
+    /// that return a bare `T`/`Vec<T>` have no channel to report failure
+    /// through and keep `Exit` semantics regardless of this setting, except
+    /// `ask_until_async`, which panics under this policy instead.
+    Return
+}
+
+lazy_static! {
+    static ref FAILURE_POLICY: Arc<Mutex<FailurePolicy>> = Arc::new(Mutex::new(FailurePolicy::Exit));
+    static ref FAILURE_HANDLER: Arc<Mutex<Option<fn(&InterviewError)>>> = Arc::new(Mutex::new(None));
+}
+
+/// Sets the global policy for handling `Interrupted`/`Eof`/`Io` failures.
+///
+/// # Arguments
+///
+/// * `policy`: new policy, see `FailurePolicy`
+///
+/// returns: ()
+pub fn set_failure_policy(policy: FailurePolicy) { *Arc::clone(&FAILURE_POLICY).lock().unwrap() = policy; }
+
+/// Registers a handler invoked with every `Interrupted`/`Eof`/`Io` failure,
+/// regardless of the current `FailurePolicy` — useful for logging or
+/// cleanup before the process exits or the error is returned to the caller.
+///
+/// # Arguments
+///
+/// * `handler`: called with a reference to the failure that occurred
+///
+/// returns: ()
+pub fn set_failure_handler(handler: fn(&InterviewError)) { *Arc::clone(&FAILURE_HANDLER).lock().unwrap() = Some(handler); }
+
+/// Runs the registered failure handler (if any) and then applies the
+/// current `FailurePolicy` to `e`.
+pub(crate) fn handle_failure(e: InterviewError) -> Result<String> {
+    if let Some(handler) = *Arc::clone(&FAILURE_HANDLER).lock().unwrap() {
+        handler(&e);
+    }
+    match *Arc::clone(&FAILURE_POLICY).lock().unwrap() {
+        FailurePolicy::Return => Err(e),
+        FailurePolicy::Exit => exit_on_failure(e)
+    }
+}
+
+/// Prints `e` and exits the process, matching the crate's legacy behaviour.
+pub(crate) fn exit_on_failure(e: InterviewError) -> ! {
+    match e {
+        InterviewError::Interrupted => std::process::exit(0),
+        _ => {
+            println!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
 }
 
 /// Enum for specifying separators for `ask_many` and its variations.
@@ -81,7 +153,7 @@ pub enum Separator<'a> {
 }
 
 #[inline(always)]
-fn get_str<S: AsRef<str>>(prompt_str: S) -> String {
+fn get_str<S: AsRef<str>>(prompt_str: S) -> Result<String> {
     let editor = Arc::clone(&EDITOR);
     let mut editor = editor.lock().unwrap();
     match editor.as_mut() {
@@ -91,15 +163,13 @@ fn get_str<S: AsRef<str>>(prompt_str: S) -> String {
                 Ok(line) => {
                     let line = line.as_str().trim();
                     editor.add_history_entry(line);
-                    line.to_owned()
-                }
-                Err(rustyline::error::ReadlineError::Interrupted) => {
-                    std::process::exit(0);
-                }
-                Err(err) => {
-                    println!("Error: {:?}", err);
-                    std::process::exit(1);
+                    Ok(line.to_owned())
                 }
+                Err(rustyline::error::ReadlineError::Interrupted) => handle_failure(InterviewError::Interrupted),
+                Err(rustyline::error::ReadlineError::Eof) => handle_failure(InterviewError::Eof),
+                Err(err) => handle_failure(InterviewError::Io {
+                    source: std::io::Error::new(std::io::ErrorKind::Other, err.to_string())
+                })
             }
         }
         None => {
@@ -108,12 +178,85 @@ fn get_str<S: AsRef<str>>(prompt_str: S) -> String {
             std::io::stdout().flush().expect("could not flush stdout");
             let mut buffer = String::new();
             let stdin = std::io::stdin();
-            stdin.read_line(&mut buffer).expect("could not read stdin");
-            buffer.trim().to_owned()
+            match stdin.read_line(&mut buffer) {
+                Ok(0) => handle_failure(InterviewError::Eof),
+                Ok(_) => Ok(buffer.trim().to_owned()),
+                Err(source) => handle_failure(InterviewError::Io { source })
+            }
+        }
+    }
+}
+
+/// Like `get_str`, but pre-fills the rustyline editable line buffer with
+/// `initial` so the user can accept it with Enter or edit it in place.
+/// Falls back to the plain `get_str` behaviour (no pre-fill possible) when
+/// `EDITOR` is `None`.
+#[inline(always)]
+fn get_str_with_initial<S: AsRef<str>>(prompt_str: S, initial: &str) -> Result<String> {
+    let editor = Arc::clone(&EDITOR);
+    let mut editor = editor.lock().unwrap();
+    match editor.as_mut() {
+        Some(editor) => {
+            let readline = editor.readline_with_initial(prompt_str.as_ref(), (initial, ""));
+            match readline {
+                Ok(line) => {
+                    let line = line.as_str().trim();
+                    editor.add_history_entry(line);
+                    Ok(line.to_owned())
+                }
+                Err(rustyline::error::ReadlineError::Interrupted) => handle_failure(InterviewError::Interrupted),
+                Err(rustyline::error::ReadlineError::Eof) => handle_failure(InterviewError::Eof),
+                Err(err) => handle_failure(InterviewError::Io {
+                    source: std::io::Error::new(std::io::ErrorKind::Other, err.to_string())
+                })
+            }
+        }
+        None => {
+            print!("{}", prompt_str.as_ref());
+            std::io::stdout().flush().expect("could not flush stdout");
+            let mut buffer = String::new();
+            let stdin = std::io::stdin();
+            match stdin.read_line(&mut buffer) {
+                Ok(0) => handle_failure(InterviewError::Eof),
+                Ok(_) => Ok(buffer.trim().to_owned()),
+                Err(source) => handle_failure(InterviewError::Io { source })
+            }
         }
     }
 }
 
+/// Reads a line like `get_str`, but always applies `FailurePolicy::Exit`
+/// semantics on failure. Used by the functions that return a bare
+/// `T`/`Vec<T>` and therefore have no channel to surface `InterviewError`
+/// through.
+#[inline(always)]
+fn get_str_or_exit<S: AsRef<str>>(prompt_str: S) -> String {
+    match get_str(prompt_str) {
+        Ok(s) => s,
+        Err(e) => exit_on_failure(e)
+    }
+}
+
+/// `get_str_with_initial` counterpart to `get_str_or_exit`.
+#[inline(always)]
+fn get_str_with_initial_or_exit<S: AsRef<str>>(prompt_str: S, initial: &str) -> String {
+    match get_str_with_initial(prompt_str, initial) {
+        Ok(s) => s,
+        Err(e) => exit_on_failure(e)
+    }
+}
+
+/// Reads a line with terminal echo disabled, for passwords, API keys, and
+/// tokens. Bypasses `EDITOR` entirely (rustyline has no no-echo mode), so,
+/// unlike `get_str`, the line is never passed to `editor.add_history_entry`.
+#[inline(always)]
+fn get_str_secret<S: AsRef<str>>(prompt_str: S) -> Result<String> {
+    match rpassword::prompt_password(prompt_str.as_ref()) {
+        Ok(line) => Ok(line.trim().to_owned()),
+        Err(source) => handle_failure(InterviewError::Io { source })
+    }
+}
+
 /// Ask the user for a value of type T.
 ///
 /// Input is read only once.
@@ -132,7 +275,7 @@ fn get_str<S: AsRef<str>>(prompt_str: S) -> String {
 /// println!("{}", s);
 /// ```
 pub fn ask<T: Askable, S: AsRef<str>>(prompt_str: S) -> Result<T> {
-    let input = get_str(prompt_str);
+    let input = get_str(prompt_str)?;
     T::convert(&input)
 }
 
@@ -159,7 +302,10 @@ pub fn ask<T: Askable, S: AsRef<str>>(prompt_str: S) -> Result<T> {
 /// ```
 pub fn ask_opt<T: Askable, S: AsRef<str>>(prompt_str: S) -> Option<T> {
     loop {
-        let input = get_str(&prompt_str);
+        let input = match get_str(&prompt_str) {
+            Ok(input) => input,
+            Err(_) => return None
+        };
         if input.is_empty() {
             return None;
         }
@@ -189,7 +335,7 @@ pub fn ask_opt<T: Askable, S: AsRef<str>>(prompt_str: S) -> Option<T> {
 /// ```
 pub fn ask_until<T: Askable, S: AsRef<str>>(prompt_str: S) -> T {
     loop {
-        let input = get_str(&prompt_str);
+        let input = get_str_or_exit(&prompt_str);
         return match T::convert(&input) {
             Ok(s) => s,
             Err(_) => {
@@ -199,6 +345,140 @@ pub fn ask_until<T: Askable, S: AsRef<str>>(prompt_str: S) -> T {
     }
 }
 
+/// Ask the user for a value of type T. The user is prompted repeatedly until
+/// a value both parses and satisfies `predicate`. On failure, the message
+/// returned by `predicate` is printed before reprompting.
+///
+/// Input is read multiple times.
+///
+/// # Arguments
+///
+/// * `prompt_str`: prompt displayed to the user
+/// * `predicate`: returns `Ok(())` if `value` is acceptable, `Err(message)` otherwise
+///
+/// returns: T
+///
+/// # Examples
+///
+/// ```
+/// use interviewer::ask_valid;
+/// let s: u8 = ask_valid("enter a value between 1 and 100: ", |v: &u8| {
+///     if (1..=100).contains(v) {
+///         Ok(())
+///     } else {
+///         Err("value must be between 1 and 100".to_string())
+///     }
+/// });
+/// println!("{}", s);
+/// ```
+pub fn ask_valid<T: Askable, S: AsRef<str>, P: Fn(&T) -> std::result::Result<(), String>>(prompt_str: S, predicate: P) -> T {
+    loop {
+        let input = get_str_or_exit(&prompt_str);
+        let value = match T::convert(&input) {
+            Ok(value) => value,
+            Err(_) => continue
+        };
+        match predicate(&value) {
+            Ok(()) => return value,
+            Err(message) => {
+                println!("{}", message);
+                continue;
+            }
+        }
+    }
+}
+
+/// Ask the user for a value of type T, showing `default` inline in the
+/// prompt (e.g. `ask_default("enter your age: ", 42)` renders as
+/// `enter your age [42]: `, the `[42]` hint spliced in right before the
+/// prompt's own trailing `:`) and returning it when the user submits an
+/// empty line.
+///
+/// Unlike `ask(...).unwrap_or(default)`, a non-empty line that fails to
+/// parse reprompts instead of silently discarding the error.
+///
+/// When rustyline is available, `default`'s text representation is also
+/// pre-filled into the editable line buffer, so pressing Enter immediately
+/// submits it; that text round-trips back through `T::convert` to
+/// `default`, same as the empty-line case. The empty-line branch itself is
+/// mainly exercised in the legacy fallback used when no rustyline editor is
+/// available, since the pre-filled buffer is rarely submitted empty.
+///
+/// Input is read multiple times.
+///
+/// # Arguments
+///
+/// * `prompt_str`: prompt displayed to the user, including its own trailing punctuation
+/// * `default`: value shown in the prompt and returned on an empty line
+///
+/// returns: T
+///
+/// # Examples
+///
+/// ```
+/// use interviewer::ask_default;
+/// let s: i32 = ask_default("enter your age: ", 42);
+/// println!("{}", s);
+/// ```
+pub fn ask_default<T: Askable + Clone + std::fmt::Display, S: AsRef<str>>(prompt_str: S, default: T) -> T {
+    let hint = format!("[{}]", default);
+    let prompt = prompt_str.as_ref();
+    let hinted = match prompt.rfind(':') {
+        Some(idx) => format!("{} {}{}", &prompt[..idx], hint, &prompt[idx..]),
+        None => format!("{} {}", prompt, hint)
+    };
+    let initial = default.to_string();
+    loop {
+        let input = get_str_with_initial_or_exit(&hinted, &initial);
+        if input.is_empty() {
+            return default;
+        }
+        if let Ok(value) = T::convert(&input) {
+            return value;
+        }
+    }
+}
+
+/// Ask the user for a secret (password, API key, token, ...) with terminal
+/// echo disabled, so it's never printed to the screen or left sitting in
+/// the rustyline history buffer.
+///
+/// Input is read only once.
+///
+/// # Arguments
+///
+/// * `prompt_str`: prompt displayed to the user
+///
+/// returns: Result<String, InterviewError>
+///
+/// # Examples
+///
+/// ```no_run
+/// use interviewer::ask_secret;
+/// let password = ask_secret("Password: ").unwrap();
+/// println!("got {} bytes", password.len());
+/// ```
+pub fn ask_secret<S: AsRef<str>>(prompt_str: S) -> Result<String> {
+    get_str_secret(prompt_str)
+}
+
+/// Wraps a secret string (e.g. from `ask_secret`) and overwrites its
+/// contents with zeroes when dropped, instead of leaving them behind in
+/// freed memory for a later allocation to reveal.
+pub struct Secret(String);
+
+impl Secret {
+    /// Wraps `value` so it is zeroed on drop.
+    pub fn new(value: String) -> Self { Secret(value) }
+
+    /// Borrows the wrapped secret.
+    pub fn expose(&self) -> &str { &self.0 }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) { self.0.zeroize(); }
+}
+
 #[inline(always)]
 fn iterator_skip<T: Iterator>(it: &mut T, len: usize) {
     match len.cmp(&2) {
@@ -212,17 +492,20 @@ fn iterator_skip<T: Iterator>(it: &mut T, len: usize) {
     }
 }
 
-macro_rules! many_main {
-    { $v:ident => $prompt_str:expr , $sep:expr } => {
-
-        let parse_quotes = *Arc::clone(&PARSE_QUOTES).lock().unwrap();
-        let s = if parse_quotes {
+/// Splits a raw line of input into tokens according to `sep`, honouring
+/// `PARSE_QUOTES` for quote-aware splitting.
+///
+/// Factored out of the `many_main!` macro so the async subsystem can
+/// tokenize a line read from an `AsyncBufRead` the same way the sync
+/// functions tokenize a line read from `get_str`.
+pub(crate) fn tokenize<S: AsRef<str>>(raw: S, sep: Separator) -> Vec<String> {
+    let parse_quotes = *Arc::clone(&PARSE_QUOTES).lock().unwrap();
+    let s = if parse_quotes {
         // replace whitespace inside of quotes such as "hello world" with
         // "helloREPRworld" to allow better parsing
-        let buffer = get_str($prompt_str);
         let mut tmp_buffer = String::new();
         let mut in_quote = false;
-        for (_, c) in buffer.char_indices() {
+        for (_, c) in raw.as_ref().char_indices() {
             if c == '"' {
                 in_quote = !in_quote;
             }
@@ -234,10 +517,10 @@ macro_rules! many_main {
         }
         tmp_buffer.trim().to_owned()
     } else {
-        get_str($prompt_str)
+        raw.as_ref().trim().to_owned()
     };
 
-    let mut s: Vec<&str> = match $sep {
+    let mut s: Vec<&str> = match sep {
         Separator::Whitespace => s.split_whitespace().collect(),
         Separator::Sequence(seq) => s.split(seq).collect(),
         Separator::SequenceTrim(seq) => {
@@ -290,8 +573,12 @@ macro_rules! many_main {
         s.pop();
     }
 
-    let $v = s.iter().map(|item| item.replace(WHITESPACE_REPR, " "));
+    s.iter().map(|item| item.replace(WHITESPACE_REPR, " ")).collect()
+}
 
+macro_rules! many_main {
+    { $v:ident => $raw:expr , $sep:expr } => {
+        let $v = tokenize($raw, $sep).into_iter();
     };
 }
 
@@ -315,7 +602,8 @@ macro_rules! many_main {
 /// println!("{:?}", s);
 /// ```
 pub fn ask_many<T: Askable, S: AsRef<str>>(prompt_str: S, sep: Separator) -> Result<Vec<T>> {
-    many_main! {s => prompt_str, sep}
+    let raw = get_str(prompt_str)?;
+    many_main! {s => raw, sep}
     let mut v = Vec::with_capacity(s.len());
     for x in s {
         v.push(Askable::convert(x)?);
@@ -344,7 +632,8 @@ pub fn ask_many<T: Askable, S: AsRef<str>>(prompt_str: S, sep: Separator) -> Res
 /// ```
 pub fn ask_many_until<T: Askable, S: AsRef<str>>(prompt_str: S, sep: Separator) -> Vec<T> {
     'outer: loop {
-        many_main! {s => &prompt_str, sep}
+        let raw = get_str_or_exit(&prompt_str);
+        many_main! {s => raw, sep}
         // Empty string could also potentially be a valid input.
         // if s.len() == 0 {
         //     continue 'outer;
@@ -386,7 +675,11 @@ pub fn ask_many_until<T: Askable, S: AsRef<str>>(prompt_str: S, sep: Separator)
 /// ```
 pub fn ask_many_opt<T: Askable, S: AsRef<str>>(prompt_str: S, sep: Separator) -> Option<Vec<T>> {
     'outer: loop {
-        many_main! {s => &prompt_str, sep}
+        let raw = match get_str(&prompt_str) {
+            Ok(raw) => raw,
+            Err(_) => return None
+        };
+        many_main! {s => raw, sep}
         if s.len() == 0 {
             return None;
         }
@@ -422,7 +715,8 @@ pub fn ask_many_opt<T: Askable, S: AsRef<str>>(prompt_str: S, sep: Separator) ->
 /// println!("{:?}", s);
 /// ```
 pub fn ask_many_opt_lazy<T: Askable, S: AsRef<str>>(prompt_str: S, sep: Separator) -> Vec<Option<T>> {
-    many_main! {s => prompt_str, sep}
+    let raw = get_str_or_exit(prompt_str);
+    many_main! {s => raw, sep}
     let mut v = Vec::with_capacity(s.len());
     for x in s {
         match Askable::convert(x) {
@@ -437,6 +731,61 @@ pub fn ask_many_opt_lazy<T: Askable, S: AsRef<str>>(prompt_str: S, sep: Separato
     v
 }
 
+/// Ask the user for multiple values of type T separated by delimiter. The
+/// user is prompted repeatedly until every value both parses and satisfies
+/// `predicate`. On failure, the message returned by `predicate` is printed
+/// before reprompting for the whole line.
+///
+/// Input is read multiple times.
+///
+/// # Arguments
+///
+/// * `prompt_str`: prompt displayed to the user
+/// * `sep`: delimiter between values
+/// * `predicate`: returns `Ok(())` if `value` is acceptable, `Err(message)` otherwise
+///
+/// returns: Vec<T>
+///
+/// # Examples
+///
+/// ```
+/// use interviewer::ask_many_valid;
+/// use interviewer::Separator::Whitespace;
+/// let s: Vec<u8> = ask_many_valid("enter some values between 1 and 100: ", Whitespace, |v: &u8| {
+///     if (1..=100).contains(v) {
+///         Ok(())
+///     } else {
+///         Err("value must be between 1 and 100".to_string())
+///     }
+/// });
+/// println!("{:?}", s);
+/// ```
+pub fn ask_many_valid<T: Askable, S: AsRef<str>, P: Fn(&T) -> std::result::Result<(), String>>(
+    prompt_str: S,
+    sep: Separator,
+    predicate: P
+) -> Vec<T> {
+    'outer: loop {
+        let raw = get_str_or_exit(&prompt_str);
+        many_main! {s => raw, sep}
+        let mut v = Vec::with_capacity(s.len());
+        for x in s {
+            let val = match Askable::convert(x) {
+                Ok(val) => val,
+                Err(_) => continue 'outer
+            };
+            match predicate(&val) {
+                Ok(()) => v.push(val),
+                Err(message) => {
+                    println!("{}", message);
+                    continue 'outer;
+                }
+            }
+        }
+        return v;
+    }
+}
+
 /// Base trait for all types that can be asked for input.
 pub trait Askable {
     /// Convert a string to a value of type T.
@@ -516,3 +865,125 @@ impl_askable!(u128);
 impl_askable!(usize);
 impl_askable!(f32);
 impl_askable!(f64);
+
+/// Trait for types that can be presented as a numbered menu by
+/// `ask_select`/`ask_select_many`.
+///
+/// Implement this once (typically for an enum) and both functions build
+/// and number the menu from `variants`/`label` automatically instead of
+/// the caller hand-rolling a bool/int prompt plus their own validation.
+pub trait Selectable: Clone {
+    /// All selectable variants, in the order they should be numbered.
+    fn variants() -> Vec<Self>
+    where Self: Sized;
+
+    /// Label shown in the menu next to this variant's number.
+    fn label(&self) -> String;
+}
+
+fn print_menu<T: Selectable>(prompt_str: &str, variants: &[T]) {
+    println!("{}", prompt_str);
+    for (i, variant) in variants.iter().enumerate() {
+        println!("  {}) {}", i + 1, variant.label());
+    }
+}
+
+/// Ask the user to pick one of `T::variants()` from a numbered menu.
+///
+/// Input is read multiple times: the user is reprompted until a number
+/// naming one of the menu entries is entered.
+///
+/// # Arguments
+///
+/// * `prompt_str`: prompt displayed above the menu
+///
+/// returns: T
+///
+/// # Examples
+///
+/// ```
+/// use interviewer::Selectable;
+///
+/// #[derive(Clone)]
+/// enum Difficulty { Easy, Medium, Hard }
+///
+/// impl Selectable for Difficulty {
+///     fn variants() -> Vec<Self> { vec![Difficulty::Easy, Difficulty::Medium, Difficulty::Hard] }
+///     fn label(&self) -> String {
+///         match self {
+///             Difficulty::Easy => "Easy".to_string(),
+///             Difficulty::Medium => "Medium".to_string(),
+///             Difficulty::Hard => "Hard".to_string()
+///         }
+///     }
+/// }
+/// ```
+pub fn ask_select<T: Selectable, S: AsRef<str>>(prompt_str: S) -> T {
+    let variants = T::variants();
+    print_menu(prompt_str.as_ref(), &variants);
+    loop {
+        let input = get_str_or_exit("> ");
+        if let Ok(choice) = usize::convert(&input) {
+            if (1..=variants.len()).contains(&choice) {
+                return variants[choice - 1].clone();
+            }
+        }
+        println!("please enter a number between 1 and {}", variants.len());
+    }
+}
+
+/// Ask the user to pick any number of `T::variants()` from a numbered menu,
+/// entered as `sep`-delimited numbers (e.g. `1,3`).
+///
+/// Input is read multiple times: the user is reprompted until every number
+/// entered names one of the menu entries.
+///
+/// # Arguments
+///
+/// * `prompt_str`: prompt displayed above the menu
+/// * `sep`: delimiter between the numbers the user enters
+///
+/// returns: Vec<T>
+///
+/// # Examples
+///
+/// ```
+/// use interviewer::{Selectable, Separator};
+///
+/// #[derive(Clone)]
+/// enum Topping { Cheese, Pepperoni, Mushroom }
+///
+/// impl Selectable for Topping {
+///     fn variants() -> Vec<Self> { vec![Topping::Cheese, Topping::Pepperoni, Topping::Mushroom] }
+///     fn label(&self) -> String {
+///         match self {
+///             Topping::Cheese => "Cheese".to_string(),
+///             Topping::Pepperoni => "Pepperoni".to_string(),
+///             Topping::Mushroom => "Mushroom".to_string()
+///         }
+///     }
+/// }
+/// ```
+pub fn ask_select_many<T: Selectable, S: AsRef<str>>(prompt_str: S, sep: Separator) -> Vec<T> {
+    let variants = T::variants();
+    print_menu(prompt_str.as_ref(), &variants);
+    loop {
+        let raw = get_str_or_exit("> ");
+        let tokens = tokenize(raw, sep);
+        let mut chosen = Vec::with_capacity(tokens.len());
+        let mut valid = !tokens.is_empty();
+        for token in &tokens {
+            match usize::convert(token) {
+                Ok(choice) if (1..=variants.len()).contains(&choice) => chosen.push(variants[choice - 1].clone()),
+                _ => {
+                    valid = false;
+                    break;
+                }
+            }
+        }
+        if valid {
+            return chosen;
+        }
+        println!("please enter one or more numbers between 1 and {}", variants.len());
+    }
+}